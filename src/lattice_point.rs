@@ -0,0 +1,482 @@
+//! Unit-tagged lattice vectors.
+//!
+//! [`LatticePoint`] wraps any [`Vector`] impl with a zero-sized `Unit` marker so that
+//! coordinates living in distinct spaces (voxel space, chunk space, world space, ...) are
+//! distinct Rust types. This follows the phantom-unit approach used by `euclid` and
+//! `glamour`: the wrapper is zero-cost, forwards `Vector`, `IntegerVector`, `Vector2`/`Vector3`/
+//! `Vector4`, `BoolVector`, `CastInteger`, `LatticeOrder`, `Fold`/`Map`/`ZipMap`, `Bounded` and
+//! the shift ops to the inner vector, and only allows arithmetic between points that share a
+//! unit. `Splat`, `Zero`, `Ones`, `Min`, `Max`, `Abs` and `RoundingOps` are not implemented
+//! for it.
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Shl, Shr, Sub, SubAssign};
+
+use crate::vector::*;
+
+/// A lattice vector tagged with a unit `U` so that, e.g., `LatticePoint<IVec3, VoxelSpace>` and
+/// `LatticePoint<IVec3, ChunkSpace>` are distinct types that cannot accidentally be added.
+///
+/// Points in different units don't type-check for arithmetic:
+///
+/// ```compile_fail
+/// # use ilattice::lattice_point::LatticePoint;
+/// # use glam::IVec3;
+/// struct VoxelSpace;
+/// struct ChunkSpace;
+///
+/// let voxel = LatticePoint::<IVec3, VoxelSpace>::new(IVec3::ZERO);
+/// let chunk = LatticePoint::<IVec3, ChunkSpace>::new(IVec3::ZERO);
+/// let _ = voxel + chunk; // error[E0308]: `VoxelSpace` != `ChunkSpace`
+/// ```
+pub struct LatticePoint<V, U> {
+    vector: V,
+    _unit: PhantomData<fn() -> U>,
+}
+
+impl<V, U> LatticePoint<V, U> {
+    /// Wraps `vector` as a point in unit `U`.
+    #[inline]
+    pub fn new(vector: V) -> Self {
+        Self {
+            vector,
+            _unit: PhantomData,
+        }
+    }
+
+    /// Unwraps the underlying vector, discarding the unit.
+    #[inline]
+    pub fn into_inner(self) -> V {
+        self.vector
+    }
+
+    /// Reinterprets this point as living in a different unit `U2`, without touching the
+    /// underlying coordinates.
+    #[inline]
+    pub fn cast_unit<U2>(self) -> LatticePoint<V, U2> {
+        LatticePoint::new(self.vector)
+    }
+}
+
+impl<V: Copy, U> Clone for LatticePoint<V, U> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<V: Copy, U> Copy for LatticePoint<V, U> {}
+
+impl<V: PartialEq, U> PartialEq for LatticePoint<V, U> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.vector == other.vector
+    }
+}
+
+impl<V: Eq, U> Eq for LatticePoint<V, U> {}
+
+impl<V: Hash, U> Hash for LatticePoint<V, U> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.vector.hash(state)
+    }
+}
+
+impl<V: fmt::Debug, U> fmt::Debug for LatticePoint<V, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("LatticePoint").field(&self.vector).finish()
+    }
+}
+
+impl<V: Vector, U> Vector for LatticePoint<V, U> {
+    type Scalar = V::Scalar;
+}
+
+impl<V: IntegerVector, U> IntegerVector for LatticePoint<V, U> {
+    type IntScalar = V::IntScalar;
+}
+
+impl<V: Vector2, U> Vector2 for LatticePoint<V, U> {
+    #[inline]
+    fn x(self) -> Self::Scalar {
+        self.vector.x()
+    }
+    #[inline]
+    fn y(self) -> Self::Scalar {
+        self.vector.y()
+    }
+    #[inline]
+    fn x_mut(&mut self) -> &mut Self::Scalar {
+        self.vector.x_mut()
+    }
+    #[inline]
+    fn y_mut(&mut self) -> &mut Self::Scalar {
+        self.vector.y_mut()
+    }
+}
+
+impl<V: Vector3, U> Vector3 for LatticePoint<V, U> {
+    #[inline]
+    fn x(self) -> Self::Scalar {
+        self.vector.x()
+    }
+    #[inline]
+    fn y(self) -> Self::Scalar {
+        self.vector.y()
+    }
+    #[inline]
+    fn z(self) -> Self::Scalar {
+        self.vector.z()
+    }
+    #[inline]
+    fn x_mut(&mut self) -> &mut Self::Scalar {
+        self.vector.x_mut()
+    }
+    #[inline]
+    fn y_mut(&mut self) -> &mut Self::Scalar {
+        self.vector.y_mut()
+    }
+    #[inline]
+    fn z_mut(&mut self) -> &mut Self::Scalar {
+        self.vector.z_mut()
+    }
+}
+
+impl<V: Vector4, U> Vector4 for LatticePoint<V, U> {
+    #[inline]
+    fn x(self) -> Self::Scalar {
+        self.vector.x()
+    }
+    #[inline]
+    fn y(self) -> Self::Scalar {
+        self.vector.y()
+    }
+    #[inline]
+    fn z(self) -> Self::Scalar {
+        self.vector.z()
+    }
+    #[inline]
+    fn w(self) -> Self::Scalar {
+        self.vector.w()
+    }
+    #[inline]
+    fn x_mut(&mut self) -> &mut Self::Scalar {
+        self.vector.x_mut()
+    }
+    #[inline]
+    fn y_mut(&mut self) -> &mut Self::Scalar {
+        self.vector.y_mut()
+    }
+    #[inline]
+    fn z_mut(&mut self) -> &mut Self::Scalar {
+        self.vector.z_mut()
+    }
+    #[inline]
+    fn w_mut(&mut self) -> &mut Self::Scalar {
+        self.vector.w_mut()
+    }
+}
+
+impl<V: BoolVector, U> BoolVector for LatticePoint<V, U> {
+    type Mask = V::Mask;
+
+    #[inline]
+    fn cmplt(self, other: Self) -> Self::Mask {
+        self.vector.cmplt(other.vector)
+    }
+    #[inline]
+    fn cmple(self, other: Self) -> Self::Mask {
+        self.vector.cmple(other.vector)
+    }
+    #[inline]
+    fn cmpgt(self, other: Self) -> Self::Mask {
+        self.vector.cmpgt(other.vector)
+    }
+    #[inline]
+    fn cmpge(self, other: Self) -> Self::Mask {
+        self.vector.cmpge(other.vector)
+    }
+    #[inline]
+    fn cmpeq(self, other: Self) -> Self::Mask {
+        self.vector.cmpeq(other.vector)
+    }
+    #[inline]
+    fn cmpne(self, other: Self) -> Self::Mask {
+        self.vector.cmpne(other.vector)
+    }
+    #[inline]
+    fn select(mask: Self::Mask, if_true: Self, if_false: Self) -> Self {
+        Self::new(V::select(mask, if_true.vector, if_false.vector))
+    }
+}
+
+impl<V: CastInteger, U> CastInteger for LatticePoint<V, U> {
+    type Int = LatticePoint<V::Int, U>;
+
+    #[inline]
+    fn cast_int(self) -> Self::Int {
+        LatticePoint::new(self.vector.cast_int())
+    }
+    #[inline]
+    fn floor_int(self) -> Self::Int {
+        LatticePoint::new(self.vector.floor_int())
+    }
+    #[inline]
+    fn ceil_int(self) -> Self::Int {
+        LatticePoint::new(self.vector.ceil_int())
+    }
+    #[inline]
+    fn round_int(self) -> Self::Int {
+        LatticePoint::new(self.vector.round_int())
+    }
+}
+
+impl<V: LatticeOrder, U> LatticeOrder for LatticePoint<V, U> {
+    type LatticeVector = WithLatticeOrd<Self>;
+    #[inline]
+    fn with_lattice_ord(self) -> Self::LatticeVector {
+        WithLatticeOrd(self)
+    }
+    #[inline]
+    fn least_upper_bound(self, other: Self) -> Self {
+        Self::new(self.vector.least_upper_bound(other.vector))
+    }
+    #[inline]
+    fn greatest_lower_bound(self, other: Self) -> Self {
+        Self::new(self.vector.greatest_lower_bound(other.vector))
+    }
+}
+
+impl<V: LatticeOrder, U> PartialOrd for WithLatticeOrd<LatticePoint<V, U>>
+where
+    WithLatticeOrd<V>: PartialOrd,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0
+            .vector
+            .with_lattice_ord()
+            .partial_cmp(&other.0.vector.with_lattice_ord())
+    }
+
+    #[inline]
+    fn lt(&self, other: &Self) -> bool {
+        self.0.vector.with_lattice_ord().lt(&other.0.vector.with_lattice_ord())
+    }
+
+    #[inline]
+    fn gt(&self, other: &Self) -> bool {
+        self.0.vector.with_lattice_ord().gt(&other.0.vector.with_lattice_ord())
+    }
+
+    #[inline]
+    fn le(&self, other: &Self) -> bool {
+        self.0.vector.with_lattice_ord().le(&other.0.vector.with_lattice_ord())
+    }
+
+    #[inline]
+    fn ge(&self, other: &Self) -> bool {
+        self.0.vector.with_lattice_ord().ge(&other.0.vector.with_lattice_ord())
+    }
+}
+
+impl<V, U> Fold<V::Scalar> for LatticePoint<V, U>
+where
+    V: Vector + Fold<V::Scalar>,
+{
+    #[inline]
+    fn fold<T>(self, init: T, f: impl Fn(<Self as Vector>::Scalar, T) -> T) -> T {
+        self.vector.fold(init, f)
+    }
+}
+
+impl<V, U> Map<V::Scalar> for LatticePoint<V, U>
+where
+    V: Vector + Map<V::Scalar>,
+{
+    #[inline]
+    fn map(self, f: impl Fn(V::Scalar) -> V::Scalar) -> Self {
+        Self::new(self.vector.map(f))
+    }
+}
+
+impl<V, U> ZipMap<V::Scalar> for LatticePoint<V, U>
+where
+    V: Vector + ZipMap<V::Scalar>,
+{
+    #[inline]
+    fn zip_map(self, other: Self, f: impl Fn(V::Scalar, V::Scalar) -> V::Scalar) -> Self {
+        Self::new(self.vector.zip_map(other.vector, f))
+    }
+}
+
+impl<V: Bounded, U> Bounded for LatticePoint<V, U> {
+    const MIN: Self = Self {
+        vector: V::MIN,
+        _unit: PhantomData,
+    };
+    const MAX: Self = Self {
+        vector: V::MAX,
+        _unit: PhantomData,
+    };
+}
+
+// Arithmetic is only ever defined between points sharing the same unit `U` -- there is no
+// `Add<LatticePoint<V, U2>>` impl, so mixing e.g. voxel-space and chunk-space points is a
+// compile error rather than a silently wrong sum.
+
+impl<V: Add<Output = V>, U> Add for LatticePoint<V, U> {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.vector + rhs.vector)
+    }
+}
+
+impl<V: AddAssign, U> AddAssign for LatticePoint<V, U> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.vector += rhs.vector;
+    }
+}
+
+impl<V: Sub<Output = V>, U> Sub for LatticePoint<V, U> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.vector - rhs.vector)
+    }
+}
+
+impl<V: SubAssign, U> SubAssign for LatticePoint<V, U> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.vector -= rhs.vector;
+    }
+}
+
+impl<V: Neg<Output = V>, U> Neg for LatticePoint<V, U> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.vector)
+    }
+}
+
+impl<V, U> Mul<V::Scalar> for LatticePoint<V, U>
+where
+    V: Vector + Mul<<V as Vector>::Scalar, Output = V>,
+{
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: V::Scalar) -> Self {
+        Self::new(self.vector * rhs)
+    }
+}
+
+impl<V, U> MulAssign<V::Scalar> for LatticePoint<V, U>
+where
+    V: Vector + MulAssign<<V as Vector>::Scalar>,
+{
+    #[inline]
+    fn mul_assign(&mut self, rhs: V::Scalar) {
+        self.vector *= rhs;
+    }
+}
+
+impl<V, U> Div<V::Scalar> for LatticePoint<V, U>
+where
+    V: Vector + Div<<V as Vector>::Scalar, Output = V>,
+{
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: V::Scalar) -> Self {
+        Self::new(self.vector / rhs)
+    }
+}
+
+impl<V, U> DivAssign<V::Scalar> for LatticePoint<V, U>
+where
+    V: Vector + DivAssign<<V as Vector>::Scalar>,
+{
+    #[inline]
+    fn div_assign(&mut self, rhs: V::Scalar) {
+        self.vector /= rhs;
+    }
+}
+
+impl<V, U, Rhs> Shl<Rhs> for LatticePoint<V, U>
+where
+    V: Shl<Rhs, Output = V>,
+{
+    type Output = Self;
+    #[inline]
+    fn shl(self, rhs: Rhs) -> Self {
+        Self::new(self.vector << rhs)
+    }
+}
+
+impl<V, U, Rhs> Shr<Rhs> for LatticePoint<V, U>
+where
+    V: Shr<Rhs, Output = V>,
+{
+    type Output = Self;
+    #[inline]
+    fn shr(self, rhs: Rhs) -> Self {
+        Self::new(self.vector >> rhs)
+    }
+}
+
+impl<V: AllShiftOps<S>, U, S> AllShiftOps<S> for LatticePoint<V, U> {
+    type UintVec = LatticePoint<V::UintVec, U>;
+}
+
+impl<V, U, Rhs> ShiftOps<Rhs> for LatticePoint<V, U> where V: ShiftOps<Rhs> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::IVec3;
+
+    struct VoxelSpace;
+    struct ChunkSpace;
+
+    type VoxelPoint = LatticePoint<IVec3, VoxelSpace>;
+    type ChunkPoint = LatticePoint<IVec3, ChunkSpace>;
+
+    #[test]
+    fn add_and_sub_stay_within_a_unit() {
+        let a = VoxelPoint::new(IVec3::new(1, 2, 3));
+        let b = VoxelPoint::new(IVec3::new(4, 5, 6));
+        assert_eq!((a + b).into_inner(), IVec3::new(5, 7, 9));
+        assert_eq!((b - a).into_inner(), IVec3::new(3, 3, 3));
+    }
+
+    #[test]
+    fn cast_unit_preserves_the_coordinates() {
+        let voxel = VoxelPoint::new(IVec3::new(1, 2, 3));
+        let chunk: ChunkPoint = voxel.cast_unit();
+        assert_eq!(chunk.into_inner(), voxel.into_inner());
+    }
+
+    #[test]
+    fn lattice_order_bounds_forward_to_the_inner_vector() {
+        let a = VoxelPoint::new(IVec3::new(1, 5, 3));
+        let b = VoxelPoint::new(IVec3::new(4, 2, 3));
+        assert_eq!(a.least_upper_bound(b).into_inner(), IVec3::new(4, 5, 3));
+        assert_eq!(a.greatest_lower_bound(b).into_inner(), IVec3::new(1, 2, 3));
+    }
+
+    #[test]
+    fn with_lattice_ord_compares_componentwise() {
+        let a = VoxelPoint::new(IVec3::new(1, 2, 3));
+        let b = VoxelPoint::new(IVec3::new(4, 5, 6));
+        let c = VoxelPoint::new(IVec3::new(4, 1, 6));
+        assert!(a.with_lattice_ord() < b.with_lattice_ord());
+        assert_eq!(a.with_lattice_ord().partial_cmp(&c.with_lattice_ord()), None);
+    }
+}
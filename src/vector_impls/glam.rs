@@ -6,8 +6,66 @@ use crate::morton::{EncodeMorton, Morton2i32, Morton2u32, Morton3i32, Morton3u32
 use core::cmp::Ordering;
 use glam::{
     const_ivec2, const_ivec3, const_uvec2, const_uvec3, const_vec2, const_vec3, const_vec3a, IVec2,
-    IVec3, UVec2, UVec3, Vec2, Vec3, Vec3A,
+    IVec3, IVec4, UVec2, UVec3, UVec4, Vec2, Vec3, Vec3A, Vec4,
 };
+use glam::{I16Vec2, I16Vec3, I64Vec2, I64Vec3, U16Vec2, U16Vec3, U64Vec2, U64Vec3};
+use glam::{BVec2, BVec3, BVec4};
+
+macro_rules! impl_mask {
+    ($bvec:ident) => {
+        impl Mask for $bvec {
+            #[inline]
+            fn any(self) -> bool {
+                self.any()
+            }
+            #[inline]
+            fn all(self) -> bool {
+                self.all()
+            }
+        }
+    };
+}
+
+impl_mask!(BVec2);
+impl_mask!(BVec3);
+impl_mask!(BVec4);
+
+macro_rules! impl_bool_vector {
+    ($vec:ident, $bvec:ident) => {
+        impl BoolVector for $vec {
+            type Mask = $bvec;
+
+            #[inline]
+            fn cmplt(self, other: Self) -> Self::Mask {
+                self.cmplt(other)
+            }
+            #[inline]
+            fn cmple(self, other: Self) -> Self::Mask {
+                self.cmple(other)
+            }
+            #[inline]
+            fn cmpgt(self, other: Self) -> Self::Mask {
+                self.cmpgt(other)
+            }
+            #[inline]
+            fn cmpge(self, other: Self) -> Self::Mask {
+                self.cmpge(other)
+            }
+            #[inline]
+            fn cmpeq(self, other: Self) -> Self::Mask {
+                self.cmpeq(other)
+            }
+            #[inline]
+            fn cmpne(self, other: Self) -> Self::Mask {
+                self.cmpne(other)
+            }
+            #[inline]
+            fn select(mask: Self::Mask, if_true: Self, if_false: Self) -> Self {
+                Self::select(mask, if_true, if_false)
+            }
+        }
+    };
+}
 
 macro_rules! impl_lattice_order {
     ($vec:ident, $scalar:ident) => {
@@ -193,6 +251,98 @@ macro_rules! impl_float_vec3_with_lattice_partial_ord {
     };
 }
 
+macro_rules! impl_integer_vec4_with_lattice_partial_ord {
+    ($vec:ident) => {
+        impl PartialOrd for WithLatticeOrd<$vec> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                if self < other {
+                    Some(Ordering::Less)
+                } else if self > other {
+                    Some(Ordering::Greater)
+                } else if self.0.x == other.0.x
+                    && self.0.y == other.0.y
+                    && self.0.z == other.0.z
+                    && self.0.w == other.0.w
+                {
+                    Some(Ordering::Equal)
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn lt(&self, other: &Self) -> bool {
+                self.0.x < other.0.x && self.0.y < other.0.y && self.0.z < other.0.z && self.0.w < other.0.w
+            }
+
+            #[inline]
+            fn gt(&self, other: &Self) -> bool {
+                self.0.x > other.0.x && self.0.y > other.0.y && self.0.z > other.0.z && self.0.w > other.0.w
+            }
+
+            #[inline]
+            fn le(&self, other: &Self) -> bool {
+                self.0.x <= other.0.x
+                    && self.0.y <= other.0.y
+                    && self.0.z <= other.0.z
+                    && self.0.w <= other.0.w
+            }
+
+            #[inline]
+            fn ge(&self, other: &Self) -> bool {
+                self.0.x >= other.0.x
+                    && self.0.y >= other.0.y
+                    && self.0.z >= other.0.z
+                    && self.0.w >= other.0.w
+            }
+        }
+    };
+}
+
+macro_rules! impl_float_vec4_with_lattice_partial_ord {
+    ($vec:ident) => {
+        impl PartialOrd for WithLatticeOrd<$vec> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                if self < other {
+                    Some(Ordering::Less)
+                } else if self > other {
+                    Some(Ordering::Greater)
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn lt(&self, other: &Self) -> bool {
+                self.0.x < other.0.x && self.0.y < other.0.y && self.0.z < other.0.z && self.0.w < other.0.w
+            }
+
+            #[inline]
+            fn gt(&self, other: &Self) -> bool {
+                self.0.x > other.0.x && self.0.y > other.0.y && self.0.z > other.0.z && self.0.w > other.0.w
+            }
+
+            #[inline]
+            fn le(&self, other: &Self) -> bool {
+                self.0.x <= other.0.x
+                    && self.0.y <= other.0.y
+                    && self.0.z <= other.0.z
+                    && self.0.w <= other.0.w
+            }
+
+            #[inline]
+            fn ge(&self, other: &Self) -> bool {
+                self.0.x >= other.0.x
+                    && self.0.y >= other.0.y
+                    && self.0.z >= other.0.z
+                    && self.0.w >= other.0.w
+            }
+        }
+    };
+}
+
 macro_rules! impl_signed_shift_ops {
     ($vec:ident, $scalar:ident, $uvec:ident) => {
         impl AllShiftOps<$scalar> for $vec {
@@ -297,6 +447,21 @@ macro_rules! impl_float_vec2 {
             fn cast_int(self) -> Self::Int {
                 Self::Int::new(self.x as $iscalar, self.y as $iscalar)
             }
+
+            #[inline]
+            fn floor_int(self) -> Self::Int {
+                self.floor().cast_int()
+            }
+
+            #[inline]
+            fn ceil_int(self) -> Self::Int {
+                self.ceil().cast_int()
+            }
+
+            #[inline]
+            fn round_int(self) -> Self::Int {
+                self.round().cast_int()
+            }
         }
     };
 }
@@ -310,6 +475,54 @@ macro_rules! impl_float_vec3 {
             fn cast_int(self) -> Self::Int {
                 Self::Int::new(self.x as $iscalar, self.y as $iscalar, self.z as $iscalar)
             }
+
+            #[inline]
+            fn floor_int(self) -> Self::Int {
+                self.floor().cast_int()
+            }
+
+            #[inline]
+            fn ceil_int(self) -> Self::Int {
+                self.ceil().cast_int()
+            }
+
+            #[inline]
+            fn round_int(self) -> Self::Int {
+                self.round().cast_int()
+            }
+        }
+    };
+}
+
+macro_rules! impl_float_vec4 {
+    ($vec:ident, $ivec:ident, $iscalar:ident) => {
+        impl CastInteger for $vec {
+            type Int = $ivec;
+
+            #[inline]
+            fn cast_int(self) -> Self::Int {
+                Self::Int::new(
+                    self.x as $iscalar,
+                    self.y as $iscalar,
+                    self.z as $iscalar,
+                    self.w as $iscalar,
+                )
+            }
+
+            #[inline]
+            fn floor_int(self) -> Self::Int {
+                self.floor().cast_int()
+            }
+
+            #[inline]
+            fn ceil_int(self) -> Self::Int {
+                self.ceil().cast_int()
+            }
+
+            #[inline]
+            fn round_int(self) -> Self::Int {
+                self.round().cast_int()
+            }
         }
     };
 }
@@ -415,6 +628,75 @@ macro_rules! impl_vec3 {
     };
 }
 
+macro_rules! impl_vec4 {
+    ($vec:ident, $scalar:ident) => {
+        impl Vector4 for $vec {
+            #[inline]
+            fn x(self) -> Self::Scalar {
+                self.x
+            }
+            #[inline]
+            fn y(self) -> Self::Scalar {
+                self.y
+            }
+            #[inline]
+            fn z(self) -> Self::Scalar {
+                self.z
+            }
+            #[inline]
+            fn w(self) -> Self::Scalar {
+                self.w
+            }
+            #[inline]
+            fn x_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.x
+            }
+            #[inline]
+            fn y_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.y
+            }
+            #[inline]
+            fn z_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.z
+            }
+            #[inline]
+            fn w_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.w
+            }
+        }
+        impl Fold<$scalar> for $vec {
+            #[inline]
+            fn fold<T>(self, init: T, f: impl Fn(<Self as Vector>::Scalar, T) -> T) -> T {
+                let mut out = init;
+                out = f(self.x, out);
+                out = f(self.y, out);
+                out = f(self.z, out);
+                out = f(self.w, out);
+                out
+            }
+        }
+        impl Map<$scalar> for $vec {
+            /// Applies `f` to all components, returning the results as `Self`.
+            #[inline]
+            fn map(self, f: impl Fn($scalar) -> $scalar) -> Self {
+                Self::new(f(self.x), f(self.y), f(self.z), f(self.w))
+            }
+        }
+        impl ZipMap<$scalar> for $vec {
+            /// Zips the components of `self` and `other`, applying `f`, and returning the results as `Self`.
+            #[inline]
+            fn zip_map(self, other: Self, f: impl Fn($scalar, $scalar) -> $scalar) -> Self {
+                Self::new(
+                    f(self.x, other.x),
+                    f(self.y, other.y),
+                    f(self.z, other.z),
+                    f(self.w, other.w),
+                )
+            }
+        }
+    };
+}
+
 // IVec2
 impl_vec2!(IVec2, i32);
 impl_integer_vector!(IVec2, 2, i32, UVec2, const_ivec2!([1; 2]));
@@ -422,6 +704,7 @@ impl_signed_vector!(IVec2);
 impl_signed_shift_ops!(IVec2, i32, UVec2);
 impl_integer_vec2_with_lattice_partial_ord!(IVec2);
 impl_lattice_order!(IVec2, i32);
+impl_bool_vector!(IVec2, BVec2);
 impl Bounded for IVec2 {
     const MIN: Self = const_ivec2!([i32::MIN; 2]);
     const MAX: Self = const_ivec2!([i32::MAX; 2]);
@@ -434,6 +717,7 @@ impl_signed_vector!(IVec3);
 impl_signed_shift_ops!(IVec3, i32, UVec3);
 impl_integer_vec3_with_lattice_partial_ord!(IVec3);
 impl_lattice_order!(IVec3, i32);
+impl_bool_vector!(IVec3, BVec3);
 impl Bounded for IVec3 {
     const MIN: Self = const_ivec3!([i32::MIN; 3]);
     const MAX: Self = const_ivec3!([i32::MAX; 3]);
@@ -445,6 +729,7 @@ impl_integer_vector!(UVec2, 2, u32, UVec2, const_uvec2!([1; 2]));
 impl_unsigned_shift_ops!(UVec2, u32);
 impl_integer_vec2_with_lattice_partial_ord!(UVec2);
 impl_lattice_order!(UVec2, u32);
+impl_bool_vector!(UVec2, BVec2);
 impl Bounded for UVec2 {
     const MIN: Self = const_uvec2!([u32::MIN; 2]);
     const MAX: Self = const_uvec2!([u32::MAX; 2]);
@@ -456,6 +741,7 @@ impl_integer_vector!(UVec3, 3, u32, UVec3, const_uvec3!([1; 3]));
 impl_unsigned_shift_ops!(UVec3, u32);
 impl_integer_vec3_with_lattice_partial_ord!(UVec3);
 impl_lattice_order!(UVec3, u32);
+impl_bool_vector!(UVec3, BVec3);
 impl Bounded for UVec3 {
     const MIN: Self = const_uvec3!([u32::MIN; 3]);
     const MAX: Self = const_uvec3!([u32::MAX; 3]);
@@ -468,6 +754,7 @@ impl_float_vec2!(Vec2, IVec2, i32);
 impl_signed_vector!(Vec2);
 impl_float_vec2_with_lattice_partial_ord!(Vec2);
 impl_lattice_order!(Vec2, f32);
+impl_bool_vector!(Vec2, BVec2);
 impl Bounded for Vec2 {
     const MIN: Self = const_vec2!([f32::MIN; 2]);
     const MAX: Self = const_vec2!([f32::MAX; 2]);
@@ -480,6 +767,7 @@ impl_float_vec3!(Vec3, IVec3, i32);
 impl_signed_vector!(Vec3);
 impl_float_vec3_with_lattice_partial_ord!(Vec3);
 impl_lattice_order!(Vec3, f32);
+impl_bool_vector!(Vec3, BVec3);
 impl Bounded for Vec3 {
     const MIN: Self = const_vec3!([f32::MIN; 3]);
     const MAX: Self = const_vec3!([f32::MAX; 3]);
@@ -492,11 +780,150 @@ impl_float_vec3!(Vec3A, IVec3, i32);
 impl_signed_vector!(Vec3A);
 impl_float_vec3_with_lattice_partial_ord!(Vec3A);
 impl_lattice_order!(Vec3A, f32);
+impl_bool_vector!(Vec3A, BVec3);
 impl Bounded for Vec3A {
     const MIN: Self = const_vec3a!([f32::MIN; 3]);
     const MAX: Self = const_vec3a!([f32::MAX; 3]);
 }
 
+// IVec4
+impl_vec4!(IVec4, i32);
+impl_integer_vector!(IVec4, 4, i32, UVec4, IVec4::ONE);
+impl_signed_vector!(IVec4);
+impl_signed_shift_ops!(IVec4, i32, UVec4);
+impl_integer_vec4_with_lattice_partial_ord!(IVec4);
+impl_lattice_order!(IVec4, i32);
+impl_bool_vector!(IVec4, BVec4);
+impl Bounded for IVec4 {
+    const MIN: Self = IVec4::new(i32::MIN, i32::MIN, i32::MIN, i32::MIN);
+    const MAX: Self = IVec4::new(i32::MAX, i32::MAX, i32::MAX, i32::MAX);
+}
+
+// UVec4
+impl_vec4!(UVec4, u32);
+impl_integer_vector!(UVec4, 4, u32, UVec4, UVec4::ONE);
+impl_unsigned_shift_ops!(UVec4, u32);
+impl_integer_vec4_with_lattice_partial_ord!(UVec4);
+impl_lattice_order!(UVec4, u32);
+impl_bool_vector!(UVec4, BVec4);
+impl Bounded for UVec4 {
+    const MIN: Self = UVec4::new(u32::MIN, u32::MIN, u32::MIN, u32::MIN);
+    const MAX: Self = UVec4::new(u32::MAX, u32::MAX, u32::MAX, u32::MAX);
+}
+
+// Vec4
+impl_vec4!(Vec4, f32);
+impl_float_vector!(Vec4, f32, IVec4, Vec4::ONE);
+impl_float_vec4!(Vec4, IVec4, i32);
+impl_signed_vector!(Vec4);
+impl_float_vec4_with_lattice_partial_ord!(Vec4);
+impl_lattice_order!(Vec4, f32);
+impl_bool_vector!(Vec4, BVec4);
+impl Bounded for Vec4 {
+    const MIN: Self = Vec4::new(f32::MIN, f32::MIN, f32::MIN, f32::MIN);
+    const MAX: Self = Vec4::new(f32::MAX, f32::MAX, f32::MAX, f32::MAX);
+}
+
+// I16Vec2
+impl_vec2!(I16Vec2, i16);
+impl_integer_vector!(I16Vec2, 2, i16, U16Vec2, I16Vec2::ONE);
+impl_signed_vector!(I16Vec2);
+impl_signed_shift_ops!(I16Vec2, i16, U16Vec2);
+impl_integer_vec2_with_lattice_partial_ord!(I16Vec2);
+impl_lattice_order!(I16Vec2, i16);
+impl_bool_vector!(I16Vec2, BVec2);
+impl Bounded for I16Vec2 {
+    const MIN: Self = I16Vec2::new(i16::MIN, i16::MIN);
+    const MAX: Self = I16Vec2::new(i16::MAX, i16::MAX);
+}
+
+// I16Vec3
+impl_vec3!(I16Vec3, i16);
+impl_integer_vector!(I16Vec3, 3, i16, U16Vec3, I16Vec3::ONE);
+impl_signed_vector!(I16Vec3);
+impl_signed_shift_ops!(I16Vec3, i16, U16Vec3);
+impl_integer_vec3_with_lattice_partial_ord!(I16Vec3);
+impl_lattice_order!(I16Vec3, i16);
+impl_bool_vector!(I16Vec3, BVec3);
+impl Bounded for I16Vec3 {
+    const MIN: Self = I16Vec3::new(i16::MIN, i16::MIN, i16::MIN);
+    const MAX: Self = I16Vec3::new(i16::MAX, i16::MAX, i16::MAX);
+}
+
+// U16Vec2
+impl_vec2!(U16Vec2, u16);
+impl_integer_vector!(U16Vec2, 2, u16, U16Vec2, U16Vec2::ONE);
+impl_unsigned_shift_ops!(U16Vec2, u16);
+impl_integer_vec2_with_lattice_partial_ord!(U16Vec2);
+impl_lattice_order!(U16Vec2, u16);
+impl_bool_vector!(U16Vec2, BVec2);
+impl Bounded for U16Vec2 {
+    const MIN: Self = U16Vec2::new(u16::MIN, u16::MIN);
+    const MAX: Self = U16Vec2::new(u16::MAX, u16::MAX);
+}
+
+// U16Vec3
+impl_vec3!(U16Vec3, u16);
+impl_integer_vector!(U16Vec3, 3, u16, U16Vec3, U16Vec3::ONE);
+impl_unsigned_shift_ops!(U16Vec3, u16);
+impl_integer_vec3_with_lattice_partial_ord!(U16Vec3);
+impl_lattice_order!(U16Vec3, u16);
+impl_bool_vector!(U16Vec3, BVec3);
+impl Bounded for U16Vec3 {
+    const MIN: Self = U16Vec3::new(u16::MIN, u16::MIN, u16::MIN);
+    const MAX: Self = U16Vec3::new(u16::MAX, u16::MAX, u16::MAX);
+}
+
+// I64Vec2
+impl_vec2!(I64Vec2, i64);
+impl_integer_vector!(I64Vec2, 2, i64, U64Vec2, I64Vec2::ONE);
+impl_signed_vector!(I64Vec2);
+impl_signed_shift_ops!(I64Vec2, i64, U64Vec2);
+impl_integer_vec2_with_lattice_partial_ord!(I64Vec2);
+impl_lattice_order!(I64Vec2, i64);
+impl_bool_vector!(I64Vec2, BVec2);
+impl Bounded for I64Vec2 {
+    const MIN: Self = I64Vec2::new(i64::MIN, i64::MIN);
+    const MAX: Self = I64Vec2::new(i64::MAX, i64::MAX);
+}
+
+// I64Vec3
+impl_vec3!(I64Vec3, i64);
+impl_integer_vector!(I64Vec3, 3, i64, U64Vec3, I64Vec3::ONE);
+impl_signed_vector!(I64Vec3);
+impl_signed_shift_ops!(I64Vec3, i64, U64Vec3);
+impl_integer_vec3_with_lattice_partial_ord!(I64Vec3);
+impl_lattice_order!(I64Vec3, i64);
+impl_bool_vector!(I64Vec3, BVec3);
+impl Bounded for I64Vec3 {
+    const MIN: Self = I64Vec3::new(i64::MIN, i64::MIN, i64::MIN);
+    const MAX: Self = I64Vec3::new(i64::MAX, i64::MAX, i64::MAX);
+}
+
+// U64Vec2
+impl_vec2!(U64Vec2, u64);
+impl_integer_vector!(U64Vec2, 2, u64, U64Vec2, U64Vec2::ONE);
+impl_unsigned_shift_ops!(U64Vec2, u64);
+impl_integer_vec2_with_lattice_partial_ord!(U64Vec2);
+impl_lattice_order!(U64Vec2, u64);
+impl_bool_vector!(U64Vec2, BVec2);
+impl Bounded for U64Vec2 {
+    const MIN: Self = U64Vec2::new(u64::MIN, u64::MIN);
+    const MAX: Self = U64Vec2::new(u64::MAX, u64::MAX);
+}
+
+// U64Vec3
+impl_vec3!(U64Vec3, u64);
+impl_integer_vector!(U64Vec3, 3, u64, U64Vec3, U64Vec3::ONE);
+impl_unsigned_shift_ops!(U64Vec3, u64);
+impl_integer_vec3_with_lattice_partial_ord!(U64Vec3);
+impl_lattice_order!(U64Vec3, u64);
+impl_bool_vector!(U64Vec3, BVec3);
+impl Bounded for U64Vec3 {
+    const MIN: Self = U64Vec3::new(u64::MIN, u64::MIN, u64::MIN);
+    const MAX: Self = U64Vec3::new(u64::MAX, u64::MAX, u64::MAX);
+}
+
 #[cfg(feature = "morton-encoding")]
 mod impl_morton {
     use super::*;
@@ -526,3 +953,422 @@ mod impl_morton {
     impl_encode_morton!(UVec2, 2, u32, Morton2u32);
     impl_encode_morton!(UVec3, 3, u32, Morton3u32);
 }
+
+/// Steps a Morton code one unit along a single axis, in Morton order, without decoding to
+/// a vector and re-encoding.
+///
+/// Implemented with dilated-integer arithmetic: each axis owns every Nth bit of the code
+/// (`mask_x`, `mask_y`, ... below). Forcing the other axes' bits to `1` before an add lets
+/// the carry ripple straight through them and land in the next bit that belongs to the
+/// target axis; masking afterwards restores the untouched lanes. Decrement mirrors this by
+/// zeroing the other axes before a wrapping subtract, so the borrow ripples the same way.
+/// Over/underflow wraps within the axis' own bits -- callers must bound-check the result
+/// against the lattice extent themselves.
+#[cfg(feature = "morton-encoding")]
+pub trait MortonAxisStep: Sized {
+    /// Increments the coordinate on `axis` by one, wrapping within that axis' bits.
+    fn inc_axis(self, axis: usize) -> Self;
+    /// Decrements the coordinate on `axis` by one, wrapping within that axis' bits.
+    fn dec_axis(self, axis: usize) -> Self;
+}
+
+#[cfg(feature = "morton-encoding")]
+macro_rules! impl_morton_axis_step {
+    ($morton:ident, $raw:ty, [$($mask:expr),+ $(,)?]) => {
+        impl MortonAxisStep for $morton {
+            #[inline]
+            fn inc_axis(self, axis: usize) -> Self {
+                let masks: [$raw; 2] = [$($mask),+];
+                let mask = masks[axis];
+                let code: $raw = self.into();
+                let sum = (code | !mask).wrapping_add(1) & mask;
+                Self::from(sum | (code & !mask))
+            }
+
+            #[inline]
+            fn dec_axis(self, axis: usize) -> Self {
+                let masks: [$raw; 2] = [$($mask),+];
+                let mask = masks[axis];
+                let code: $raw = self.into();
+                let diff = (code & mask).wrapping_sub(1) & mask;
+                Self::from(diff | (code & !mask))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "morton-encoding")]
+macro_rules! impl_morton_axis_step_3d {
+    ($morton:ident, $raw:ty, $mask_x:expr, $mask_y:expr, $mask_z:expr) => {
+        impl MortonAxisStep for $morton {
+            #[inline]
+            fn inc_axis(self, axis: usize) -> Self {
+                let masks: [$raw; 3] = [$mask_x, $mask_y, $mask_z];
+                let mask = masks[axis];
+                let code: $raw = self.into();
+                let sum = (code | !mask).wrapping_add(1) & mask;
+                Self::from(sum | (code & !mask))
+            }
+
+            #[inline]
+            fn dec_axis(self, axis: usize) -> Self {
+                let masks: [$raw; 3] = [$mask_x, $mask_y, $mask_z];
+                let mask = masks[axis];
+                let code: $raw = self.into();
+                let diff = (code & mask).wrapping_sub(1) & mask;
+                Self::from(diff | (code & !mask))
+            }
+        }
+    };
+}
+
+// 2D codes interleave 2 lanes, so each axis owns every other bit.
+#[cfg(feature = "morton-encoding")]
+impl_morton_axis_step!(Morton2i32, u64, [0x5555_5555_5555_5555, 0xaaaa_aaaa_aaaa_aaaa]);
+#[cfg(feature = "morton-encoding")]
+impl_morton_axis_step!(Morton2u32, u64, [0x5555_5555_5555_5555, 0xaaaa_aaaa_aaaa_aaaa]);
+
+// 3D codes interleave 3 lanes, so each axis owns every third bit of the low 96 bits.
+#[cfg(feature = "morton-encoding")]
+impl_morton_axis_step_3d!(
+    Morton3i32,
+    u128,
+    0x2492_4924_9249_2492_4924_9249,
+    0x4924_9249_2492_4924_9249_2492,
+    0x9249_2492_4924_9249_2492_4924
+);
+#[cfg(feature = "morton-encoding")]
+impl_morton_axis_step_3d!(
+    Morton3u32,
+    u128,
+    0x2492_4924_9249_2492_4924_9249,
+    0x4924_9249_2492_4924_9249_2492,
+    0x9249_2492_4924_9249_2492_4924
+);
+
+#[cfg(feature = "mint")]
+mod impl_mint {
+    //! `mint` interop for every vector type this chunk registers.
+    //!
+    //! Note on `From`/`Into`: neither `mint::VectorN`/`PointN` nor `glam::*Vec*` is a type
+    //! local to this crate, so Rust's orphan rules forbid writing `impl From<IVec2> for
+    //! mint::Vector2<i32>` (or the reverse) here -- only glam itself (under its own `mint`
+    //! feature) can implement that trait for its own vector types. The functions below give
+    //! the same conversions without a trait impl. [`LatticePoint`] *is* local to this crate, so
+    //! a `LatticePoint` over any of the 2D and 3D vector types gets real `From`/`Into` impls
+    //! instead; a `LatticePoint` over `IVec4`, `UVec4` or `Vec4` has none.
+    use super::*;
+    use crate::lattice_point::LatticePoint;
+
+    macro_rules! impl_mint_conv_vec2 {
+        ($to_vector:ident, $to_point:ident, $from_vector:ident, $from_point:ident, $vec:ident, $scalar:ident) => {
+            #[inline]
+            pub fn $to_vector(v: $vec) -> mint::Vector2<$scalar> {
+                mint::Vector2 { x: v.x, y: v.y }
+            }
+            #[inline]
+            pub fn $to_point(v: $vec) -> mint::Point2<$scalar> {
+                mint::Point2 { x: v.x, y: v.y }
+            }
+            #[inline]
+            pub fn $from_vector(v: mint::Vector2<$scalar>) -> $vec {
+                $vec::new(v.x, v.y)
+            }
+            #[inline]
+            pub fn $from_point(p: mint::Point2<$scalar>) -> $vec {
+                $vec::new(p.x, p.y)
+            }
+        };
+    }
+
+    macro_rules! impl_mint_conv_vec3 {
+        ($to_vector:ident, $to_point:ident, $from_vector:ident, $from_point:ident, $vec:ident, $scalar:ident) => {
+            #[inline]
+            pub fn $to_vector(v: $vec) -> mint::Vector3<$scalar> {
+                mint::Vector3 { x: v.x, y: v.y, z: v.z }
+            }
+            #[inline]
+            pub fn $to_point(v: $vec) -> mint::Point3<$scalar> {
+                mint::Point3 { x: v.x, y: v.y, z: v.z }
+            }
+            #[inline]
+            pub fn $from_vector(v: mint::Vector3<$scalar>) -> $vec {
+                $vec::new(v.x, v.y, v.z)
+            }
+            #[inline]
+            pub fn $from_point(p: mint::Point3<$scalar>) -> $vec {
+                $vec::new(p.x, p.y, p.z)
+            }
+        };
+    }
+
+    macro_rules! impl_mint_conv_vec4 {
+        ($to_vector:ident, $from_vector:ident, $vec:ident, $scalar:ident) => {
+            #[inline]
+            pub fn $to_vector(v: $vec) -> mint::Vector4<$scalar> {
+                mint::Vector4 { x: v.x, y: v.y, z: v.z, w: v.w }
+            }
+            #[inline]
+            pub fn $from_vector(v: mint::Vector4<$scalar>) -> $vec {
+                $vec::new(v.x, v.y, v.z, v.w)
+            }
+        };
+    }
+
+    impl_mint_conv_vec2!(ivec2_to_mint_vector, ivec2_to_mint_point, ivec2_from_mint_vector, ivec2_from_mint_point, IVec2, i32);
+    impl_mint_conv_vec2!(uvec2_to_mint_vector, uvec2_to_mint_point, uvec2_from_mint_vector, uvec2_from_mint_point, UVec2, u32);
+    impl_mint_conv_vec2!(vec2_to_mint_vector, vec2_to_mint_point, vec2_from_mint_vector, vec2_from_mint_point, Vec2, f32);
+    impl_mint_conv_vec2!(i16vec2_to_mint_vector, i16vec2_to_mint_point, i16vec2_from_mint_vector, i16vec2_from_mint_point, I16Vec2, i16);
+    impl_mint_conv_vec2!(u16vec2_to_mint_vector, u16vec2_to_mint_point, u16vec2_from_mint_vector, u16vec2_from_mint_point, U16Vec2, u16);
+    impl_mint_conv_vec2!(i64vec2_to_mint_vector, i64vec2_to_mint_point, i64vec2_from_mint_vector, i64vec2_from_mint_point, I64Vec2, i64);
+    impl_mint_conv_vec2!(u64vec2_to_mint_vector, u64vec2_to_mint_point, u64vec2_from_mint_vector, u64vec2_from_mint_point, U64Vec2, u64);
+
+    impl_mint_conv_vec3!(ivec3_to_mint_vector, ivec3_to_mint_point, ivec3_from_mint_vector, ivec3_from_mint_point, IVec3, i32);
+    impl_mint_conv_vec3!(uvec3_to_mint_vector, uvec3_to_mint_point, uvec3_from_mint_vector, uvec3_from_mint_point, UVec3, u32);
+    impl_mint_conv_vec3!(vec3_to_mint_vector, vec3_to_mint_point, vec3_from_mint_vector, vec3_from_mint_point, Vec3, f32);
+    impl_mint_conv_vec3!(vec3a_to_mint_vector, vec3a_to_mint_point, vec3a_from_mint_vector, vec3a_from_mint_point, Vec3A, f32);
+    impl_mint_conv_vec3!(i16vec3_to_mint_vector, i16vec3_to_mint_point, i16vec3_from_mint_vector, i16vec3_from_mint_point, I16Vec3, i16);
+    impl_mint_conv_vec3!(u16vec3_to_mint_vector, u16vec3_to_mint_point, u16vec3_from_mint_vector, u16vec3_from_mint_point, U16Vec3, u16);
+    impl_mint_conv_vec3!(i64vec3_to_mint_vector, i64vec3_to_mint_point, i64vec3_from_mint_vector, i64vec3_from_mint_point, I64Vec3, i64);
+    impl_mint_conv_vec3!(u64vec3_to_mint_vector, u64vec3_to_mint_point, u64vec3_from_mint_vector, u64vec3_from_mint_point, U64Vec3, u64);
+
+    impl_mint_conv_vec4!(ivec4_to_mint_vector, ivec4_from_mint_vector, IVec4, i32);
+    impl_mint_conv_vec4!(uvec4_to_mint_vector, uvec4_from_mint_vector, UVec4, u32);
+    impl_mint_conv_vec4!(vec4_to_mint_vector, vec4_from_mint_vector, Vec4, f32);
+
+    macro_rules! impl_mint_vec2 {
+        ($vec:ident, $scalar:ident) => {
+            impl<Unit> From<LatticePoint<$vec, Unit>> for mint::Vector2<$scalar> {
+                #[inline]
+                fn from(p: LatticePoint<$vec, Unit>) -> Self {
+                    let v = p.into_inner();
+                    mint::Vector2 { x: v.x, y: v.y }
+                }
+            }
+            impl<Unit> From<mint::Vector2<$scalar>> for LatticePoint<$vec, Unit> {
+                #[inline]
+                fn from(v: mint::Vector2<$scalar>) -> Self {
+                    Self::new($vec::new(v.x, v.y))
+                }
+            }
+            impl<Unit> From<LatticePoint<$vec, Unit>> for mint::Point2<$scalar> {
+                #[inline]
+                fn from(p: LatticePoint<$vec, Unit>) -> Self {
+                    let v = p.into_inner();
+                    mint::Point2 { x: v.x, y: v.y }
+                }
+            }
+            impl<Unit> From<mint::Point2<$scalar>> for LatticePoint<$vec, Unit> {
+                #[inline]
+                fn from(p: mint::Point2<$scalar>) -> Self {
+                    Self::new($vec::new(p.x, p.y))
+                }
+            }
+        };
+    }
+
+    macro_rules! impl_mint_vec3 {
+        ($vec:ident, $scalar:ident) => {
+            impl<Unit> From<LatticePoint<$vec, Unit>> for mint::Vector3<$scalar> {
+                #[inline]
+                fn from(p: LatticePoint<$vec, Unit>) -> Self {
+                    let v = p.into_inner();
+                    mint::Vector3 { x: v.x, y: v.y, z: v.z }
+                }
+            }
+            impl<Unit> From<mint::Vector3<$scalar>> for LatticePoint<$vec, Unit> {
+                #[inline]
+                fn from(v: mint::Vector3<$scalar>) -> Self {
+                    Self::new($vec::new(v.x, v.y, v.z))
+                }
+            }
+            impl<Unit> From<LatticePoint<$vec, Unit>> for mint::Point3<$scalar> {
+                #[inline]
+                fn from(p: LatticePoint<$vec, Unit>) -> Self {
+                    let v = p.into_inner();
+                    mint::Point3 { x: v.x, y: v.y, z: v.z }
+                }
+            }
+            impl<Unit> From<mint::Point3<$scalar>> for LatticePoint<$vec, Unit> {
+                #[inline]
+                fn from(p: mint::Point3<$scalar>) -> Self {
+                    Self::new($vec::new(p.x, p.y, p.z))
+                }
+            }
+        };
+    }
+
+    impl_mint_vec2!(IVec2, i32);
+    impl_mint_vec2!(UVec2, u32);
+    impl_mint_vec2!(Vec2, f32);
+    impl_mint_vec2!(I16Vec2, i16);
+    impl_mint_vec2!(U16Vec2, u16);
+    impl_mint_vec2!(I64Vec2, i64);
+    impl_mint_vec2!(U64Vec2, u64);
+    impl_mint_vec3!(IVec3, i32);
+    impl_mint_vec3!(UVec3, u32);
+    impl_mint_vec3!(Vec3, f32);
+    impl_mint_vec3!(Vec3A, f32);
+    impl_mint_vec3!(I16Vec3, i16);
+    impl_mint_vec3!(U16Vec3, u16);
+    impl_mint_vec3!(I64Vec3, i64);
+    impl_mint_vec3!(U64Vec3, u64);
+}
+
+#[cfg(feature = "bytemuck")]
+mod impl_bytemuck {
+    //! Zero-copy slice reinterpretation for every vector type this chunk registers, for bulk
+    //! voxel buffer handoff. Relies on glam's own `bytemuck` feature (enabled transitively by
+    //! this crate's `bytemuck` feature) to provide the `Pod`/`Zeroable` impls on the vector
+    //! types themselves.
+    use super::*;
+
+    macro_rules! impl_bytemuck_cast_slice {
+        ($to_array:ident, $to_vec:ident, $vec:ident, $scalar:ident, $dim:literal) => {
+            #[inline]
+            pub fn $to_array(slice: &[$vec]) -> &[[$scalar; $dim]] {
+                bytemuck::cast_slice(slice)
+            }
+
+            #[inline]
+            pub fn $to_vec(slice: &[[$scalar; $dim]]) -> &[$vec] {
+                bytemuck::cast_slice(slice)
+            }
+        };
+    }
+
+    impl_bytemuck_cast_slice!(ivec2_as_arrays, arrays_as_ivec2, IVec2, i32, 2);
+    impl_bytemuck_cast_slice!(ivec3_as_arrays, arrays_as_ivec3, IVec3, i32, 3);
+    impl_bytemuck_cast_slice!(uvec2_as_arrays, arrays_as_uvec2, UVec2, u32, 2);
+    impl_bytemuck_cast_slice!(uvec3_as_arrays, arrays_as_uvec3, UVec3, u32, 3);
+    impl_bytemuck_cast_slice!(vec2_as_arrays, arrays_as_vec2, Vec2, f32, 2);
+    impl_bytemuck_cast_slice!(vec3_as_arrays, arrays_as_vec3, Vec3, f32, 3);
+    // `Vec3A` is SIMD-padded to 16 bytes, not the 12 bytes of `[f32; 3]`, so it casts to/from
+    // `[f32; 4]` instead -- casting it as `[f32; 3]` would panic on most slice lengths and
+    // reinterpret garbage on the rest.
+    impl_bytemuck_cast_slice!(vec3a_as_arrays, arrays_as_vec3a, Vec3A, f32, 4);
+    impl_bytemuck_cast_slice!(ivec4_as_arrays, arrays_as_ivec4, IVec4, i32, 4);
+    impl_bytemuck_cast_slice!(uvec4_as_arrays, arrays_as_uvec4, UVec4, u32, 4);
+    impl_bytemuck_cast_slice!(vec4_as_arrays, arrays_as_vec4, Vec4, f32, 4);
+    impl_bytemuck_cast_slice!(i16vec2_as_arrays, arrays_as_i16vec2, I16Vec2, i16, 2);
+    impl_bytemuck_cast_slice!(i16vec3_as_arrays, arrays_as_i16vec3, I16Vec3, i16, 3);
+    impl_bytemuck_cast_slice!(u16vec2_as_arrays, arrays_as_u16vec2, U16Vec2, u16, 2);
+    impl_bytemuck_cast_slice!(u16vec3_as_arrays, arrays_as_u16vec3, U16Vec3, u16, 3);
+    impl_bytemuck_cast_slice!(i64vec2_as_arrays, arrays_as_i64vec2, I64Vec2, i64, 2);
+    impl_bytemuck_cast_slice!(i64vec3_as_arrays, arrays_as_i64vec3, I64Vec3, i64, 3);
+    impl_bytemuck_cast_slice!(u64vec2_as_arrays, arrays_as_u64vec2, U64Vec2, u64, 2);
+    impl_bytemuck_cast_slice!(u64vec3_as_arrays, arrays_as_u64vec3, U64Vec3, u64, 3);
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn vec3_as_arrays_round_trips_a_multi_element_slice() {
+            let vecs = [Vec3::new(1.0, 2.0, 3.0), Vec3::new(4.0, 5.0, 6.0), Vec3::new(7.0, 8.0, 9.0)];
+            let arrays = vec3_as_arrays(&vecs);
+            assert_eq!(arrays, [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+            assert_eq!(arrays_as_vec3(arrays), vecs);
+        }
+
+        #[test]
+        fn vec3a_as_arrays_round_trips_a_multi_element_slice_at_its_padded_width() {
+            // `Vec3A` is padded to 16 bytes, so it round-trips through `[f32; 4]`, not
+            // `[f32; 3]` -- the fourth element is the padding lane, not a real w component.
+            let vecs = [
+                Vec3A::new(1.0, 2.0, 3.0),
+                Vec3A::new(4.0, 5.0, 6.0),
+                Vec3A::new(7.0, 8.0, 9.0),
+            ];
+            let arrays = vec3a_as_arrays(&vecs);
+            assert_eq!(arrays.len(), vecs.len());
+            assert_eq!(arrays_as_vec3a(arrays), vecs);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "morton-encoding"))]
+mod morton_axis_step_tests {
+    use super::*;
+
+    #[test]
+    fn inc_axis_then_dec_axis_round_trips_through_decode() {
+        let v = IVec3::new(10, 20, 30);
+        let code = Morton3i32::from(v);
+
+        let stepped = code.inc_axis(0).dec_axis(0);
+        assert_eq!(IVec3::from(stepped), v);
+
+        let stepped = code.inc_axis(1).dec_axis(1);
+        assert_eq!(IVec3::from(stepped), v);
+
+        let stepped = code.inc_axis(2).dec_axis(2);
+        assert_eq!(IVec3::from(stepped), v);
+    }
+
+    #[test]
+    fn inc_axis_steps_only_the_target_axis() {
+        let v = IVec3::new(10, 20, 30);
+        let code = Morton3i32::from(v);
+
+        let x_stepped = IVec3::from(code.inc_axis(0));
+        assert_eq!(x_stepped, IVec3::new(11, 20, 30));
+
+        let y_stepped = IVec3::from(code.inc_axis(1));
+        assert_eq!(y_stepped, IVec3::new(10, 21, 30));
+
+        let z_stepped = IVec3::from(code.inc_axis(2));
+        assert_eq!(z_stepped, IVec3::new(10, 20, 31));
+    }
+
+    #[test]
+    fn dec_axis_steps_only_the_target_axis() {
+        let v = IVec3::new(10, 20, 30);
+        let code = Morton3i32::from(v);
+
+        let x_stepped = IVec3::from(code.dec_axis(0));
+        assert_eq!(x_stepped, IVec3::new(9, 20, 30));
+    }
+
+    #[test]
+    fn inc_axis_2d_steps_only_the_target_axis() {
+        let v = IVec2::new(5, 7);
+        let code = Morton2i32::from(v);
+
+        let x_stepped = IVec2::from(code.inc_axis(0));
+        assert_eq!(x_stepped, IVec2::new(6, 7));
+
+        let y_stepped = IVec2::from(code.inc_axis(1));
+        assert_eq!(y_stepped, IVec2::new(5, 8));
+    }
+}
+
+#[cfg(test)]
+mod cast_integer_rounding_tests {
+    use super::*;
+
+    #[test]
+    fn floor_int_rounds_toward_negative_infinity() {
+        assert_eq!(Vec2::new(-0.5, 0.5).floor_int(), IVec2::new(-1, 0));
+        assert_eq!(Vec3::new(-0.5, 1.5, -1.5).floor_int(), IVec3::new(-1, 1, -2));
+    }
+
+    #[test]
+    fn ceil_int_rounds_toward_positive_infinity() {
+        assert_eq!(Vec2::new(-0.5, 0.5).ceil_int(), IVec2::new(0, 1));
+        assert_eq!(Vec3::new(-0.5, 1.5, -1.5).ceil_int(), IVec3::new(0, 2, -1));
+    }
+
+    #[test]
+    fn round_int_rounds_to_nearest() {
+        assert_eq!(Vec2::new(-0.4, 0.4).round_int(), IVec2::new(0, 0));
+        assert_eq!(Vec2::new(-0.6, 0.6).round_int(), IVec2::new(-1, 1));
+    }
+
+    #[test]
+    fn cast_int_still_truncates_toward_zero() {
+        // `cast_int` is the pre-existing plain `as` cast; it should keep truncating rather
+        // than flooring, unlike the new rounding-mode-aware methods above.
+        assert_eq!(Vec2::new(-0.5, 0.5).cast_int(), IVec2::new(0, 0));
+    }
+}